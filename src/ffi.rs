@@ -7,7 +7,7 @@
 //!
 //! For block height parameters, always use the result of [block_height_mip0]
 //! or [mip_block_height] unless the format explicitly specifies a block height.
-use crate::{surface::BlockDim, BlockHeight};
+use crate::{blockdepth::block_depth, surface::BlockDim, BlockHeight};
 
 /// See [crate::surface::swizzle_surface].
 ///
@@ -34,14 +34,14 @@ pub unsafe extern "C" fn swizzle_surface(
     array_count: u32,
 ) {
     let source = std::slice::from_raw_parts(source, source_len);
-    let mut destination = std::slice::from_raw_parts_mut(destination, destination_len);
+    let destination = std::slice::from_raw_parts_mut(destination, destination_len);
 
     crate::surface::swizzle_surface_inner::<false>(
         width,
         height,
         depth,
         source,
-        &mut destination,
+        destination,
         block_dim,
         Some(BlockHeight::new(block_height_mip0).unwrap()),
         bytes_per_pixel,
@@ -76,14 +76,14 @@ pub unsafe extern "C" fn deswizzle_surface(
     array_count: u32,
 ) {
     let source = std::slice::from_raw_parts(source, source_len);
-    let mut destination = std::slice::from_raw_parts_mut(destination, destination_len);
+    let destination = std::slice::from_raw_parts_mut(destination, destination_len);
 
     crate::surface::swizzle_surface_inner::<true>(
         width,
         height,
         depth,
         source,
-        &mut destination,
+        destination,
         block_dim,
         Some(BlockHeight::new(block_height_mip0).unwrap()),
         bytes_per_pixel,
@@ -175,7 +175,7 @@ pub unsafe extern "C" fn swizzle_block_linear(
         source,
         destination,
         BlockHeight::new(block_height).unwrap(),
-        depth,
+        block_depth(depth),
         bytes_per_pixel,
     )
 }
@@ -209,7 +209,7 @@ pub unsafe extern "C" fn deswizzle_block_linear(
         source,
         destination,
         BlockHeight::new(block_height).unwrap(),
-        depth,
+        block_depth(depth),
         bytes_per_pixel,
     )
 }
@@ -235,6 +235,33 @@ pub unsafe extern "C" fn swizzled_mip_size(
     )
 }
 
+/// See [crate::swizzle::swizzled_offset].
+///
+/// # Safety
+/// `block_height` must be one of the supported values in [BlockHeight].
+#[no_mangle]
+pub unsafe extern "C" fn swizzled_offset(
+    x: u32,
+    y: u32,
+    z: u32,
+    width: u32,
+    height: u32,
+    depth: u32,
+    block_height: u32,
+    bytes_per_pixel: u32,
+) -> usize {
+    crate::swizzle::swizzled_offset(
+        x,
+        y,
+        z,
+        width,
+        height,
+        depth,
+        BlockHeight::new(block_height).unwrap(),
+        bytes_per_pixel,
+    )
+}
+
 /// See [crate::swizzle::deswizzled_mip_size].
 #[no_mangle]
 pub extern "C" fn deswizzled_mip_size(
@@ -372,6 +399,54 @@ mod tests {
         assert_eq!(expected, &actual[..]);
     }
 
+    #[test]
+    fn swizzle_rgba_33_33_33() {
+        let input = include_bytes!("../block_linear/33_33_33_rgba.bin");
+        let expected = include_bytes!("../block_linear/33_33_33_rgba_tiled.bin");
+
+        let size = unsafe { swizzled_mip_size(33, 33, 33, 1, 4) };
+        let mut actual = vec![0u8; size];
+        unsafe {
+            swizzle_block_linear(
+                33,
+                33,
+                33,
+                input.as_ptr(),
+                input.len(),
+                actual.as_mut_ptr(),
+                actual.len(),
+                1,
+                4,
+            );
+        }
+
+        assert!(expected == &actual[..]);
+    }
+
+    #[test]
+    fn deswizzle_rgba_33_33_33() {
+        let input = include_bytes!("../block_linear/33_33_33_rgba_tiled.bin");
+        let expected = include_bytes!("../block_linear/33_33_33_rgba.bin");
+
+        let size = deswizzled_mip_size(33, 33, 33, 4);
+        let mut actual = vec![0u8; size];
+        unsafe {
+            deswizzle_block_linear(
+                33,
+                33,
+                33,
+                input.as_ptr(),
+                input.len(),
+                actual.as_mut_ptr(),
+                actual.len(),
+                1,
+                4,
+            );
+        }
+
+        assert!(expected == &actual[..]);
+    }
+
     #[test]
     fn mip_block_height_bcn() {
         assert_eq!(4, unsafe {
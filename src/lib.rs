@@ -45,6 +45,7 @@ const GOB_SIZE_IN_BYTES: u32 = GOB_WIDTH_IN_BYTES * GOB_HEIGHT_IN_BYTES;
 ///
 /// Texture file formats differ in how they encode the block height parameter.
 /// Some formats may encode block height using log2, so a block height of 8 would be encoded as 3.
+/// Use [BlockHeight::from_log2] for these formats.
 /// For formats that do not explicitly store block height, see [block_height_mip0].
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -126,6 +127,30 @@ impl BlockHeight {
             _ => None,
         }
     }
+
+    /// Attempts to construct a block height from its base 2 logarithm `value`.
+    /// Returns [None] if `value` is not the log2 of a supported block height.
+    /// # Examples
+    /**
+    ```rust
+    use tegra_swizzle::BlockHeight;
+
+    assert_eq!(Some(BlockHeight::One), BlockHeight::from_log2(0));
+    assert_eq!(Some(BlockHeight::Eight), BlockHeight::from_log2(3));
+    assert_eq!(None, BlockHeight::from_log2(6));
+    ```
+    */
+    pub fn from_log2(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(BlockHeight::One),
+            1 => Some(BlockHeight::Two),
+            2 => Some(BlockHeight::Four),
+            3 => Some(BlockHeight::Eight),
+            4 => Some(BlockHeight::Sixteen),
+            5 => Some(BlockHeight::ThirtyTwo),
+            _ => None,
+        }
+    }
 }
 
 const fn height_in_blocks(height: u32, block_height: u32) -> u32 {
@@ -155,7 +180,7 @@ assert_eq!(n, div_round_up(n, 1));
  */
 #[inline]
 pub const fn div_round_up(x: u32, d: u32) -> u32 {
-    (x + d - 1) / d
+    x.div_ceil(d)
 }
 
 const fn width_in_gobs(width: u32, bytes_per_pixel: u32) -> u32 {
@@ -167,6 +192,16 @@ mod tests {
     use super::*;
     use crate::swizzle::{deswizzled_mip_size, swizzled_mip_size};
 
+    use alloc::vec::Vec;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// Generate mostly unique input data with a fixed seed.
+    pub(crate) fn random_bytes(len: usize) -> Vec<u8> {
+        let seed = [13u8; 32];
+        let mut rng: StdRng = SeedableRng::from_seed(seed);
+        (0..len).map(|_| rng.gen_range::<u8, _>(0..=255)).collect()
+    }
+
     #[test]
     fn width_in_gobs_block16() {
         assert_eq!(20, width_in_gobs(320 / 4, 16));
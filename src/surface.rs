@@ -135,6 +135,7 @@ impl BlockDim {
 ///     1,
 /// );
 /// ```
+#[allow(clippy::too_many_arguments)]
 pub fn swizzle_surface(
     width: u32,
     height: u32,
@@ -250,6 +251,7 @@ pub fn swizzle_surface(
 ///     1,
 /// );
 /// ```
+#[allow(clippy::too_many_arguments)]
 pub fn deswizzle_surface(
     width: u32,
     height: u32,
@@ -302,6 +304,7 @@ pub fn deswizzle_surface(
     Ok(result)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn swizzle_surface_inner<const DESWIZZLE: bool>(
     width: u32,
     height: u32,
@@ -368,6 +371,7 @@ pub(crate) fn swizzle_surface_inner<const DESWIZZLE: bool>(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn surface_destination<const DESWIZZLE: bool>(
     width: u32,
     height: u32,
@@ -417,7 +421,7 @@ fn surface_destination<const DESWIZZLE: bool>(
     Ok(vec![0u8; surface_size])
 }
 
-fn validate_surface(
+pub(crate) fn validate_surface(
     width: u32,
     height: u32,
     depth: u32,
@@ -446,13 +450,32 @@ fn validate_surface(
     }
 }
 
-// TODO: Add examples.
 /// Calculates the size in bytes for the tiled data for the given surface.
 /// Compare with [deswizzled_surface_size].
 ///
 /// Dimensions should be in pixels.
 ///
 /// Use a `block_height_mip0` of [None] to infer the block height from the specified dimensions.
+///
+/// # Examples
+/// The size includes the padding for all mipmaps and the alignment between array layers.
+///
+/// ```rust
+/// use tegra_swizzle::surface::{BlockDim, swizzled_surface_size};
+///
+/// // 128x128 BC7 cube map with 8 mipmaps.
+/// assert_eq!(
+///     147456,
+///     swizzled_surface_size(128, 128, 1, BlockDim::block_4x4(), None, 16, 8, 6)
+/// );
+///
+/// // 16x16x16 R8G8B8A8 3D texture with no mipmaps.
+/// assert_eq!(
+///     16384,
+///     swizzled_surface_size(16, 16, 16, BlockDim::uncompressed(), None, 4, 1, 1)
+/// );
+/// ```
+#[allow(clippy::too_many_arguments)]
 pub fn swizzled_surface_size(
     width: u32,
     height: u32,
@@ -501,11 +524,29 @@ pub fn swizzled_surface_size(
     }
 }
 
-// TODO: Add examples.
 /// Calculates the size in bytes for the untiled or linear data for the given surface.
 /// Compare with [swizzled_surface_size].
 ///
 /// Dimensions should be in pixels.
+///
+/// # Examples
+/// The size is the sum of the size of each mipmap for all array layers without any padding.
+///
+/// ```rust
+/// use tegra_swizzle::surface::{BlockDim, deswizzled_surface_size};
+///
+/// // 128x128 BC7 cube map with 8 mipmaps.
+/// assert_eq!(
+///     131232,
+///     deswizzled_surface_size(128, 128, 1, BlockDim::block_4x4(), 16, 8, 6)
+/// );
+///
+/// // 16x16x16 R8G8B8A8 3D texture with no mipmaps.
+/// assert_eq!(
+///     16384,
+///     deswizzled_surface_size(16, 16, 16, BlockDim::uncompressed(), 4, 1, 1)
+/// );
+/// ```
 pub fn deswizzled_surface_size(
     width: u32,
     height: u32,
@@ -531,6 +572,7 @@ pub fn deswizzled_surface_size(
     layer_size * layer_count as usize
 }
 
+#[allow(clippy::too_many_arguments)]
 fn swizzle_mipmap<const DESWIZZLE: bool>(
     with: u32,
     height: u32,
@@ -586,9 +628,8 @@ fn swizzle_mipmap<const DESWIZZLE: bool>(
 
 #[cfg(test)]
 mod tests {
-    use core::u32;
-
     use super::*;
+    use crate::tests::random_bytes;
 
     // Use helper functions to shorten the test cases.
     fn swizzle_length(
@@ -633,6 +674,7 @@ mod tests {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn swizzle_length_3d(
         width: u32,
         height: u32,
@@ -662,6 +704,7 @@ mod tests {
         .len()
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn deswizzle_length_3d(
         width: u32,
         height: u32,
@@ -851,6 +894,90 @@ mod tests {
         assert_eq!(0, deswizzle_length(u32::MAX, u32::MAX, 0, false, 4, 1, 0));
     }
 
+    fn swizzle_deswizzle_surface_random(
+        width: u32,
+        height: u32,
+        block_dim: BlockDim,
+        bytes_per_pixel: u32,
+        mipmap_count: u32,
+        layer_count: u32,
+    ) {
+        let deswizzled_size = deswizzled_surface_size(
+            width,
+            height,
+            1,
+            block_dim,
+            bytes_per_pixel,
+            mipmap_count,
+            layer_count,
+        );
+
+        let input = random_bytes(deswizzled_size);
+
+        let swizzled = swizzle_surface(
+            width,
+            height,
+            1,
+            &input,
+            block_dim,
+            None,
+            bytes_per_pixel,
+            mipmap_count,
+            layer_count,
+        )
+        .unwrap();
+
+        let deswizzled = deswizzle_surface(
+            width,
+            height,
+            1,
+            &swizzled,
+            block_dim,
+            None,
+            bytes_per_pixel,
+            mipmap_count,
+            layer_count,
+        )
+        .unwrap();
+
+        assert_eq!(input, deswizzled);
+    }
+
+    #[test]
+    fn swizzle_deswizzle_surface_formats() {
+        // BC1, BC3/BC7, R8G8B8A8, and R32G32B32A32.
+        let formats = [
+            (BlockDim::block_4x4(), 8),
+            (BlockDim::block_4x4(), 16),
+            (BlockDim::uncompressed(), 4),
+            (BlockDim::uncompressed(), 16),
+        ];
+        for (block_dim, bytes_per_pixel) in formats {
+            swizzle_deswizzle_surface_random(64, 64, block_dim, bytes_per_pixel, 1, 1);
+            swizzle_deswizzle_surface_random(128, 128, block_dim, bytes_per_pixel, 8, 6);
+            swizzle_deswizzle_surface_random(300, 200, block_dim, bytes_per_pixel, 9, 1);
+        }
+    }
+
+    #[test]
+    fn surface_sizes_bc7_mipmaps_to_1x1() {
+        // Mipmaps smaller than 4x4 pixels still take up a full compressed block.
+        // Each tiled mipmap takes up at least a single GOB.
+        let deswizzled_sizes: Vec<_> = (1..=5)
+            .map(|mipmap_count| {
+                deswizzled_surface_size(16, 16, 1, BlockDim::block_4x4(), 16, mipmap_count, 1)
+            })
+            .collect();
+        assert_eq!(vec![256, 320, 336, 352, 368], deswizzled_sizes);
+
+        let swizzled_sizes: Vec<_> = (1..=5)
+            .map(|mipmap_count| {
+                swizzled_surface_size(16, 16, 1, BlockDim::block_4x4(), None, 16, mipmap_count, 1)
+            })
+            .collect();
+        assert_eq!(vec![512, 1024, 1536, 2048, 2560], swizzled_sizes);
+    }
+
     #[test]
     fn swizzle_surface_not_enough_data() {
         let input = [0, 0, 0, 0];
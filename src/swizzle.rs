@@ -4,8 +4,9 @@
 //! Most texture formats should use the surface functions
 //! to handle mipmap and array layer alignment.
 use crate::{
-    blockdepth::block_depth, div_round_up, height_in_blocks, width_in_gobs, BlockHeight,
-    SwizzleError, GOB_HEIGHT_IN_BYTES, GOB_SIZE_IN_BYTES, GOB_WIDTH_IN_BYTES,
+    blockdepth::block_depth, div_round_up, height_in_blocks, surface::validate_surface,
+    width_in_gobs, BlockHeight, SwizzleError, GOB_HEIGHT_IN_BYTES, GOB_SIZE_IN_BYTES,
+    GOB_WIDTH_IN_BYTES,
 };
 use alloc::{vec, vec::Vec};
 
@@ -13,6 +14,7 @@ use alloc::{vec, vec::Vec};
 ///
 /// Returns [SwizzleError::NotEnoughData] if `source` does not have
 /// at least as many bytes as the result of [deswizzled_mip_size].
+/// Returns [SwizzleError::InvalidSurface] if the dimensions would overflow in size calculations.
 ///
 /// # Examples
 /// Uncompressed formats like R8G8B8A8 can use the width and height in pixels.
@@ -58,9 +60,9 @@ pub fn swizzle_block_linear(
     block_height: BlockHeight,
     bytes_per_pixel: u32,
 ) -> Result<Vec<u8>, SwizzleError> {
-    let mut destination =
-        vec![0u8; swizzled_mip_size(width, height, depth, block_height, bytes_per_pixel)];
+    validate_surface(width, height, depth, bytes_per_pixel, 1)?;
 
+    // Validate the source length before attempting to allocate.
     let expected_size = deswizzled_mip_size(width, height, depth, bytes_per_pixel);
     if source.len() < expected_size {
         return Err(SwizzleError::NotEnoughData {
@@ -69,6 +71,9 @@ pub fn swizzle_block_linear(
         });
     }
 
+    let mut destination =
+        vec![0u8; swizzled_mip_size(width, height, depth, block_height, bytes_per_pixel)];
+
     // TODO: This should be a parameter since it varies by mipmap?
     let block_depth = block_depth(depth);
 
@@ -89,6 +94,7 @@ pub fn swizzle_block_linear(
 ///
 /// Returns [SwizzleError::NotEnoughData] if `source` does not have
 /// at least as many bytes as the result of [swizzled_mip_size].
+/// Returns [SwizzleError::InvalidSurface] if the dimensions would overflow in size calculations.
 ///
 /// # Examples
 /// Uncompressed formats like R8G8B8A8 can use the width and height in pixels.
@@ -134,8 +140,9 @@ pub fn deswizzle_block_linear(
     block_height: BlockHeight,
     bytes_per_pixel: u32,
 ) -> Result<Vec<u8>, SwizzleError> {
-    let mut destination = vec![0u8; deswizzled_mip_size(width, height, depth, bytes_per_pixel)];
+    validate_surface(width, height, depth, bytes_per_pixel, 1)?;
 
+    // Validate the source length before attempting to allocate.
     let expected_size = swizzled_mip_size(width, height, depth, block_height, bytes_per_pixel);
     if source.len() < expected_size {
         return Err(SwizzleError::NotEnoughData {
@@ -144,6 +151,8 @@ pub fn deswizzle_block_linear(
         });
     }
 
+    let mut destination = vec![0u8; deswizzled_mip_size(width, height, depth, bytes_per_pixel)];
+
     // TODO: This should be a parameter since it varies by mipmap?
     let block_depth = block_depth(depth);
 
@@ -160,6 +169,7 @@ pub fn deswizzle_block_linear(
     Ok(destination)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn swizzle_inner<const DESWIZZLE: bool>(
     width: u32,
     height: u32,
@@ -181,12 +191,16 @@ pub(crate) fn swizzle_inner<const DESWIZZLE: bool>(
     let block_size_in_bytes = GOB_SIZE_IN_BYTES * block_width * block_height * block_depth;
     let block_height_in_bytes = GOB_HEIGHT_IN_BYTES * block_height;
 
+    // Offsets are calculated using usize since tiled surfaces can be larger than u32::MAX bytes.
+    let row_size_in_bytes = width as usize * bytes_per_pixel as usize;
+    let linear_slice_size = row_size_in_bytes * height as usize;
+
     // Tiling is defined as a mapping from byte coordinates x,y,z -> x',y',z'.
     // We step a GOB of bytes at a time to optimize the inner loop with SIMD loads/stores.
     // GOBs always use the same tiling patterns, so we can optimize tiling complete 64x8 GOBs.
     // The partially filled GOBs along the right and bottom edge use a slower per byte implementation.
     for z0 in 0..depth {
-        let offset_z = gob_address_z(z0, block_height, block_depth, slice_size as u32);
+        let offset_z = gob_address_z(z0, block_height, block_depth, slice_size);
 
         // Step by a GOB of bytes in y.
         for y0 in (0..height).step_by(GOB_HEIGHT_IN_BYTES as usize) {
@@ -203,28 +217,28 @@ pub(crate) fn swizzle_inner<const DESWIZZLE: bool>(
             for x0 in (0..(width * bytes_per_pixel)).step_by(GOB_WIDTH_IN_BYTES as usize) {
                 let offset_x = gob_address_x(x0, block_size_in_bytes);
 
-                let gob_address = offset_z as usize + offset_y as usize + offset_x as usize;
+                let gob_address = offset_z + offset_y + offset_x;
 
                 // Check if we can use the fast path.
-                if x0 + GOB_WIDTH_IN_BYTES < width * bytes_per_pixel
-                    && y0 + GOB_HEIGHT_IN_BYTES < height
+                if x0 as usize + (GOB_WIDTH_IN_BYTES as usize) < row_size_in_bytes
+                    && y0 as usize + (GOB_HEIGHT_IN_BYTES as usize) < height as usize
                 {
-                    let linear_offset = (z0 * width * height * bytes_per_pixel)
-                        + (y0 * width * bytes_per_pixel)
-                        + x0;
+                    let linear_offset = (z0 as usize * linear_slice_size)
+                        + (y0 as usize * row_size_in_bytes)
+                        + x0 as usize;
 
                     // Use optimized code to reassign bytes.
                     if DESWIZZLE {
                         deswizzle_complete_gob(
-                            &mut destination[linear_offset as usize..],
+                            &mut destination[linear_offset..],
                             &source[gob_address..],
-                            width as usize * bytes_per_pixel as usize,
+                            row_size_in_bytes,
                         );
                     } else {
                         swizzle_complete_gob(
                             &mut destination[gob_address..],
-                            &source[linear_offset as usize..],
-                            width as usize * bytes_per_pixel as usize,
+                            &source[linear_offset..],
+                            row_size_in_bytes,
                         );
                     }
                 } else {
@@ -247,6 +261,7 @@ pub(crate) fn swizzle_inner<const DESWIZZLE: bool>(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn swizzle_deswizzle_gob<const DESWIZZLE: bool>(
     destination: &mut [u8],
     source: &[u8],
@@ -258,20 +273,23 @@ fn swizzle_deswizzle_gob<const DESWIZZLE: bool>(
     bytes_per_pixel: u32,
     gob_address: usize,
 ) {
+    let row_size_in_bytes = width as usize * bytes_per_pixel as usize;
+    let linear_slice_size = row_size_in_bytes * height as usize;
+
     for y in 0..GOB_HEIGHT_IN_BYTES {
         for x in 0..GOB_WIDTH_IN_BYTES {
-            if y0 + y < height && x0 + x < width * bytes_per_pixel {
+            let linear_x = x0 as usize + x as usize;
+            let linear_y = y0 as usize + y as usize;
+            if linear_y < height as usize && linear_x < row_size_in_bytes {
                 let swizzled_offset = gob_address + gob_offset(x, y) as usize;
-                let linear_offset = (z0 * width * height * bytes_per_pixel)
-                    + ((y0 + y) * width * bytes_per_pixel)
-                    + x0
-                    + x;
+                let linear_offset =
+                    (z0 as usize * linear_slice_size) + (linear_y * row_size_in_bytes) + linear_x;
 
                 // Swap the addresses for tiling vs untiling.
                 if DESWIZZLE {
-                    destination[linear_offset as usize] = source[swizzled_offset];
+                    destination[linear_offset] = source[swizzled_offset];
                 } else {
-                    destination[swizzled_offset] = source[linear_offset as usize];
+                    destination[swizzled_offset] = source[linear_offset];
                 }
             }
         }
@@ -282,11 +300,14 @@ fn swizzle_deswizzle_gob<const DESWIZZLE: bool>(
 // https://github.com/Ryujinx/Ryujinx/blob/master/Ryujinx.Graphics.Texture/BlockLinearLayout.cs
 // License MIT: https://github.com/Ryujinx/Ryujinx/blob/master/LICENSE.txt.
 fn slice_size(block_height: u32, block_depth: u32, width_in_gobs: u32, height: u32) -> usize {
-    let rob_size = GOB_SIZE_IN_BYTES * block_height * block_depth * width_in_gobs;
-    div_round_up(height, block_height * GOB_HEIGHT_IN_BYTES) as usize * rob_size as usize
+    let rob_size = GOB_SIZE_IN_BYTES as usize
+        * block_height as usize
+        * block_depth as usize
+        * width_in_gobs as usize;
+    div_round_up(height, block_height * GOB_HEIGHT_IN_BYTES) as usize * rob_size
 }
 
-fn gob_address_z(z: u32, block_height: u32, block_depth: u32, slice_size: u32) -> u32 {
+fn gob_address_z(z: u32, block_height: u32, block_depth: u32, slice_size: usize) -> usize {
     // Each "column" of blocks has block_depth many blocks.
     // A 16x16x16 RGBA8 3d texture has the following untiled GOB indices.
     //  0, 16,
@@ -294,7 +315,8 @@ fn gob_address_z(z: u32, block_height: u32, block_depth: u32, slice_size: u32) -
     // ...
     // 14, 30
     // 15, 31
-    (z / block_depth * slice_size) + ((z & (block_depth - 1)) * GOB_SIZE_IN_BYTES * block_height)
+    (z / block_depth) as usize * slice_size
+        + ((z & (block_depth - 1)) * GOB_SIZE_IN_BYTES * block_height) as usize
 }
 
 fn gob_address_y(
@@ -302,16 +324,17 @@ fn gob_address_y(
     block_height_in_bytes: u32,
     block_size_in_bytes: u32,
     image_width_in_gobs: u32,
-) -> u32 {
+) -> usize {
     let block_y = y / block_height_in_bytes;
     let block_inner_row = y % block_height_in_bytes / GOB_HEIGHT_IN_BYTES;
-    block_y * block_size_in_bytes * image_width_in_gobs + block_inner_row * GOB_SIZE_IN_BYTES
+    block_y as usize * block_size_in_bytes as usize * image_width_in_gobs as usize
+        + (block_inner_row * GOB_SIZE_IN_BYTES) as usize
 }
 
 // Code for offset_x and offset_y adapted from examples in the Tegra TRM v1.3 page 1217.
-fn gob_address_x(x: u32, block_size_in_bytes: u32) -> u32 {
+fn gob_address_x(x: u32, block_size_in_bytes: u32) -> usize {
     let block_x = x / GOB_WIDTH_IN_BYTES;
-    block_x * block_size_in_bytes
+    block_x as usize * block_size_in_bytes as usize
 }
 
 // Code taken from examples in Tegra TRM v1.3 page 1218.
@@ -365,6 +388,62 @@ fn swizzle_gob_row(dst: &mut [u8], dst_offset: usize, src: &[u8], src_offset: us
     dst[0..16].copy_from_slice(&src[0..16]);
 }
 
+/// Calculates the offset in bytes into the tiled data for the pixel at location (`x`, `y`, `z`)
+/// using the block linear algorithm.
+///
+/// This is useful for reading individual pixels or blocks
+/// without untiling the entire mipmap with [deswizzle_block_linear].
+/// The parameters should match the values used for [swizzle_block_linear] or [deswizzle_block_linear].
+/// The coordinates should be within the surface dimensions.
+/// Coordinates or dimensions outside the surface are not checked
+/// and may panic on overflow or return an offset past the end of the tiled data.
+///
+/// The bytes for a pixel are contiguous in the tiled data
+/// if `bytes_per_pixel` is one of 1, 2, 4, 8, or 16.
+///
+/// # Examples
+/// For compressed formats with multiple pixels in a block, use coordinates and dimensions in blocks.
+/**
+```rust
+use tegra_swizzle::{block_height_mip0, div_round_up, swizzle::swizzled_offset};
+
+// BC7 has 4x4 pixel blocks that each take up 16 bytes.
+let width = div_round_up(512, 4);
+let height = div_round_up(512, 4);
+let block_height = block_height_mip0(height);
+
+// Find the block containing the pixel at (300, 200).
+let offset = swizzled_offset(300 / 4, 200 / 4, 0, width, height, 1, block_height, 16);
+```
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn swizzled_offset(
+    x: u32,
+    y: u32,
+    z: u32,
+    width: u32,
+    height: u32,
+    depth: u32,
+    block_height: BlockHeight,
+    bytes_per_pixel: u32,
+) -> usize {
+    let block_height = block_height as u32;
+    let block_depth = block_depth(depth);
+    let width_in_gobs = width_in_gobs(width, bytes_per_pixel);
+
+    let slice_size = slice_size(block_height, block_depth, width_in_gobs, height);
+    let block_size_in_bytes = GOB_SIZE_IN_BYTES * block_height * block_depth;
+    let block_height_in_bytes = GOB_HEIGHT_IN_BYTES * block_height;
+
+    // Tiling is defined over byte coordinates rather than pixel coordinates.
+    let x = x * bytes_per_pixel;
+
+    gob_address_z(z, block_height, block_depth, slice_size)
+        + gob_address_y(y, block_height_in_bytes, block_size_in_bytes, width_in_gobs)
+        + gob_address_x(x, block_size_in_bytes)
+        + gob_offset(x, y) as usize
+}
+
 /// Calculates the size in bytes for the tiled data for the given dimensions for the block linear format.
 ///
 /// The result of [swizzled_mip_size] will always be aligned to the GOB size of 512 bytes.
@@ -464,37 +543,296 @@ pub const fn deswizzled_mip_size(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{block_height_mip0, tests::random_bytes};
+
+    fn swizzle_deswizzle_random(
+        width: u32,
+        height: u32,
+        block_height: BlockHeight,
+        bytes_per_pixel: u32,
+    ) {
+        let deswizzled_size = deswizzled_mip_size(width, height, 1, bytes_per_pixel);
 
-    use rand::{rngs::StdRng, Rng, SeedableRng};
+        let input = random_bytes(deswizzled_size);
+
+        let swizzled =
+            swizzle_block_linear(width, height, 1, &input, block_height, bytes_per_pixel).unwrap();
+
+        let deswizzled =
+            deswizzle_block_linear(width, height, 1, &swizzled, block_height, bytes_per_pixel)
+                .unwrap();
+
+        assert_eq!(input, deswizzled);
+    }
 
     #[test]
     fn swizzle_deswizzle_bytes_per_pixel() {
-        let width = 312;
-        let height = 575;
-        let block_height = BlockHeight::Eight;
-
         // Test a value that isn't 4, 8, or 16.
         // Non standard values won't show up in practice.
         // The tiling algorithm should still handle these cases.
-        let bytes_per_pixel = 12;
+        swizzle_deswizzle_random(312, 575, BlockHeight::Eight, 12);
 
-        let deswizzled_size = deswizzled_mip_size(width, height, 1, bytes_per_pixel);
+        // Values that don't evenly divide the 64 byte GOB width split pixels across GOBs.
+        for bytes_per_pixel in [3, 5, 6, 7] {
+            swizzle_deswizzle_random(312, 575, BlockHeight::Eight, bytes_per_pixel);
+            swizzle_deswizzle_random(17, 9, BlockHeight::One, bytes_per_pixel);
+        }
+    }
+
+    #[test]
+    fn swizzle_deswizzle_small_bytes_per_pixel() {
+        // R8, RG8, and RGBA16 formats.
+        // An 8x8 R8 surface has less than 16 bytes per GOB row.
+        for bytes_per_pixel in [1, 2, 8] {
+            swizzle_deswizzle_random(8, 8, BlockHeight::One, bytes_per_pixel);
+            swizzle_deswizzle_random(256, 256, BlockHeight::Sixteen, bytes_per_pixel);
+            swizzle_deswizzle_random(300, 100, BlockHeight::Four, bytes_per_pixel);
+        }
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn gob_addresses_larger_than_u32() {
+        // The tiled size of large surfaces can exceed u32::MAX even if the linear size does not.
+        assert_eq!(1 << 32, gob_address_z(16, 1, 16, 1 << 32));
+        assert_eq!(
+            1 << 32,
+            gob_address_y(16 * 8 * 2048, 16 * 8, 16 * GOB_SIZE_IN_BYTES, 256)
+        );
+        assert_eq!(1 << 32, gob_address_x(64 << 25, 128));
+    }
+
+    // Block linear offset for 2D surfaces written directly from the Tegra X1 TRM formula.
+    // This avoids the GOB address functions used by swizzled_offset and the tiling code.
+    fn reference_offset(
+        x: u32,
+        y: u32,
+        width: u32,
+        block_height: BlockHeight,
+        bytes_per_pixel: u32,
+    ) -> usize {
+        let x = (x * bytes_per_pixel) as usize;
+        let y = y as usize;
+        let gobs_per_row = (width * bytes_per_pixel).div_ceil(64) as usize;
+        let block_size = block_height as usize * 512;
+        let block_height_in_bytes = block_height as usize * 8;
+
+        let block = (y / block_height_in_bytes) * gobs_per_row * block_size + (x / 64) * block_size;
+        let gob = (y % block_height_in_bytes) / 8 * 512;
+        let byte =
+            (x % 64) / 32 * 256 + (y % 8) / 2 * 64 + (x % 32) / 16 * 32 + (y % 2) * 16 + x % 16;
+        block + gob + byte
+    }
 
-        // Generate mostly unique input data.
-        let seed = [13u8; 32];
-        let mut rng: StdRng = SeedableRng::from_seed(seed);
-        let input: Vec<_> = (0..deswizzled_size)
-            .map(|_| rng.gen_range::<u8, _>(0..=255))
+    #[test]
+    fn swizzled_offsets_non_square() {
+        // Offsets computed by hand for R8G8B8A8 with 16 GOBs per block.
+        // 256x128 has 16 GOBs per row and a single row of blocks.
+        for (x, y, expected) in [(1, 1, 20), (0, 8, 512), (16, 0, 8192), (255, 127, 131068)] {
+            assert_eq!(
+                expected,
+                reference_offset(x, y, 256, BlockHeight::Sixteen, 4)
+            );
+            assert_eq!(
+                expected,
+                swizzled_offset(x, y, 0, 256, 128, 1, BlockHeight::Sixteen, 4)
+            );
+        }
+
+        // 64x512 has 4 GOBs per row and 4 rows of blocks.
+        for (x, y, expected) in [(16, 0, 8192), (0, 128, 32768), (63, 511, 131068)] {
+            assert_eq!(
+                expected,
+                reference_offset(x, y, 64, BlockHeight::Sixteen, 4)
+            );
+            assert_eq!(
+                expected,
+                swizzled_offset(x, y, 0, 64, 512, 1, BlockHeight::Sixteen, 4)
+            );
+        }
+
+        // The last block of 64x512 BC7.
+        assert_eq!(
+            32752,
+            reference_offset(15, 127, 16, BlockHeight::Sixteen, 16)
+        );
+        assert_eq!(
+            32752,
+            swizzled_offset(15, 127, 0, 16, 128, 1, BlockHeight::Sixteen, 16)
+        );
+    }
+
+    fn swizzle_deswizzle_matches_offsets(
+        width: u32,
+        height: u32,
+        block_height: BlockHeight,
+        bytes_per_pixel: u32,
+    ) {
+        // Use unique 4 byte indices similar to the test data in block_linear.
+        // Unique tiled data also checks that untiling ignores the GOB padding.
+        let linear_size = deswizzled_mip_size(width, height, 1, bytes_per_pixel);
+        let linear: Vec<_> = (0..linear_size as u32 / 4)
+            .flat_map(|i| i.to_le_bytes())
+            .collect();
+        let tiled_size = swizzled_mip_size(width, height, 1, block_height, bytes_per_pixel);
+        let tiled: Vec<_> = (0..tiled_size as u32 / 4)
+            .flat_map(|i| i.to_le_bytes())
             .collect();
 
         let swizzled =
-            swizzle_block_linear(width, height, 1, &input, block_height, bytes_per_pixel).unwrap();
-
+            swizzle_block_linear(width, height, 1, &linear, block_height, bytes_per_pixel).unwrap();
         let deswizzled =
+            deswizzle_block_linear(width, height, 1, &tiled, block_height, bytes_per_pixel)
+                .unwrap();
+
+        let bpp = bytes_per_pixel as usize;
+        for y in 0..height {
+            for x in 0..width {
+                let offset = reference_offset(x, y, width, block_height, bytes_per_pixel);
+                assert_eq!(
+                    offset,
+                    swizzled_offset(x, y, 0, width, height, 1, block_height, bytes_per_pixel)
+                );
+                let linear_offset = (y as usize * width as usize + x as usize) * bpp;
+                assert_eq!(
+                    &linear[linear_offset..linear_offset + bpp],
+                    &swizzled[offset..offset + bpp],
+                    "swizzle {width}x{height} pixel ({x}, {y})"
+                );
+                assert_eq!(
+                    &tiled[offset..offset + bpp],
+                    &deswizzled[linear_offset..linear_offset + bpp],
+                    "deswizzle {width}x{height} pixel ({x}, {y})"
+                );
+            }
+        }
+
+        // Untiling should be the inverse of tiling.
+        let new_linear =
             deswizzle_block_linear(width, height, 1, &swizzled, block_height, bytes_per_pixel)
                 .unwrap();
+        assert_eq!(linear, new_linear);
+    }
 
-        assert_eq!(input, deswizzled);
+    #[test]
+    fn swizzle_deswizzle_power_of_two() {
+        // R8G8B8A8, BC1, and BC3/BC7 for 4x4 up to 1024x1024 pixels.
+        // Expected offsets come from reference_offset instead of the tiling code.
+        for (block_size, bytes_per_pixel) in [(1, 4), (4, 8), (4, 16)] {
+            for i in 2..=10 {
+                let width = (1 << i) / block_size;
+                let height = (1 << i) / block_size;
+                let block_height = block_height_mip0(height);
+                swizzle_deswizzle_matches_offsets(width, height, block_height, bytes_per_pixel);
+            }
+        }
+    }
+
+    #[test]
+    fn swizzle_deswizzle_non_square() {
+        // Check that the GOB address calculations don't assume width and height are equal.
+        swizzle_deswizzle_matches_offsets(256, 128, block_height_mip0(128), 4);
+        swizzle_deswizzle_matches_offsets(128, 256, block_height_mip0(256), 4);
+        swizzle_deswizzle_matches_offsets(64, 512, block_height_mip0(512), 4);
+        swizzle_deswizzle_matches_offsets(512, 64, block_height_mip0(64), 4);
+        // BC7 256x128 and 64x512.
+        swizzle_deswizzle_matches_offsets(64, 32, block_height_mip0(32), 16);
+        swizzle_deswizzle_matches_offsets(16, 128, block_height_mip0(128), 16);
+    }
+
+    #[test]
+    fn reference_offsets_power_of_two_fixtures() {
+        // Check the reference formula against the emulator data for a single mipmap.
+        let fixtures: [(u32, u32, &[u8], &[u8]); 6] = [
+            (
+                64,
+                4,
+                include_bytes!("../block_linear/64_rgba.bin"),
+                include_bytes!("../block_linear/64_rgba_tiled.bin"),
+            ),
+            (
+                128,
+                4,
+                include_bytes!("../block_linear/128_rgba.bin"),
+                include_bytes!("../block_linear/128_rgba_tiled.bin"),
+            ),
+            (
+                256,
+                4,
+                include_bytes!("../block_linear/256_rgba.bin"),
+                include_bytes!("../block_linear/256_rgba_tiled.bin"),
+            ),
+            (
+                512,
+                4,
+                include_bytes!("../block_linear/512_rgba.bin"),
+                include_bytes!("../block_linear/512_rgba_tiled.bin"),
+            ),
+            (
+                128 / 4,
+                8,
+                include_bytes!("../block_linear/128_bc1.bin"),
+                include_bytes!("../block_linear/128_bc1_tiled.bin"),
+            ),
+            (
+                128 / 4,
+                16,
+                include_bytes!("../block_linear/128_bc7.bin"),
+                include_bytes!("../block_linear/128_bc7_tiled.bin"),
+            ),
+        ];
+
+        for (size, bytes_per_pixel, deswizzled, swizzled) in fixtures {
+            let block_height = block_height_mip0(size);
+            let bpp = bytes_per_pixel as usize;
+            for y in 0..size {
+                for x in 0..size {
+                    let offset = reference_offset(x, y, size, block_height, bytes_per_pixel);
+                    let linear_offset = (y as usize * size as usize + x as usize) * bpp;
+                    assert_eq!(
+                        &deswizzled[linear_offset..linear_offset + bpp],
+                        &swizzled[offset..offset + bpp],
+                        "{size}x{size} pixel ({x}, {y})"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn swizzled_offsets_bc7_64_64() {
+        let swizzled = include_bytes!("../block_linear/64_bc7_tiled.bin");
+        let deswizzled = include_bytes!("../block_linear/64_bc7.bin");
+
+        for y in 0..16 {
+            for x in 0..16 {
+                let offset = swizzled_offset(x, y, 0, 16, 16, 1, BlockHeight::Two, 16);
+                let linear_offset = (y as usize * 16 + x as usize) * 16;
+                assert_eq!(
+                    &deswizzled[linear_offset..linear_offset + 16],
+                    &swizzled[offset..offset + 16]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn swizzled_offsets_rgba_16_16_16() {
+        let swizzled = include_bytes!("../block_linear/16_16_16_rgba_tiled.bin");
+        let deswizzled = include_bytes!("../block_linear/16_16_16_rgba.bin");
+
+        for z in 0..16 {
+            for y in 0..16 {
+                for x in 0..16 {
+                    let offset = swizzled_offset(x, y, z, 16, 16, 16, BlockHeight::One, 4);
+                    let linear_offset = ((z as usize * 16 + y as usize) * 16 + x as usize) * 4;
+                    assert_eq!(
+                        &deswizzled[linear_offset..linear_offset + 4],
+                        &swizzled[offset..offset + 4]
+                    );
+                }
+            }
+        }
     }
 
     #[test]
@@ -521,6 +859,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn swizzle_potential_overflow() {
+        let result = swizzle_block_linear(u32::MAX, 1, 1, &[], BlockHeight::One, 4);
+        assert_eq!(
+            result,
+            Err(SwizzleError::InvalidSurface {
+                width: u32::MAX,
+                height: 1,
+                depth: 1,
+                bytes_per_pixel: 4,
+                mipmap_count: 1
+            })
+        );
+    }
+
+    #[test]
+    fn deswizzle_potential_out_of_memory() {
+        // Test a large 3D texture that likely won't fit in memory.
+        // The input is clearly too small, so this should error instead of panic.
+        let result = deswizzle_block_linear(65535, 65535, 65535, &[0; 4], BlockHeight::One, 4);
+        assert_eq!(
+            result,
+            Err(SwizzleError::InvalidSurface {
+                width: 65535,
+                height: 65535,
+                depth: 65535,
+                bytes_per_pixel: 4,
+                mipmap_count: 1
+            })
+        );
+    }
+
     #[test]
     fn swizzle_bc7_64_64_not_enough_data() {
         let result = swizzle_block_linear(